@@ -0,0 +1,286 @@
+//! Proc-macro sibling of `struct-metadata`.
+//!
+//! This crate provides the `#[derive(Described)]` macro re-exported from the main
+//! `struct_metadata` crate. It walks a struct (and each of its fields) at compile time and emits
+//! a `Described` impl that builds the equivalent `Descriptor`/`Entry` tree at runtime, reading:
+//!
+//! - doc comments, into `docs`
+//! - `#[metadata(key = "value", ...)]`, into the `metadata` map
+//! - `#[metadata(default)]`, into `has_default`
+//! - `#[metadata(alias = "...")]` (repeatable), into `aliases`
+//! - the standard `#[deprecated(since = "...", note = "...")]`, into `deprecation`
+//! - `#[metadata(stable_since = "...")]` / `#[metadata(unstable)]`, into `stability`
+//! - `#[metadata_type(T)]` on the struct, to build `Descriptor<T>` with the struct-level
+//!   `#[metadata(key = "value", ...)]` pairs assigned onto `T::default()`'s fields instead of
+//!   collected into the default `HashMap<&'static str, &'static str>`
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr, Meta, Token};
+
+/// Derives `struct_metadata::Described` for a struct with named fields.
+#[proc_macro_derive(Described, attributes(metadata, metadata_type))]
+pub fn derive_described(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Parses one comma-separated `key = "value"` / bare-flag entry inside a `#[metadata(...)]` list.
+enum MetadataItem {
+    /// `key = "value"`
+    KeyValue(syn::Path, LitStr),
+    /// a bare flag, e.g. `default` or `unstable`
+    Flag(syn::Path),
+}
+
+impl Parse for MetadataItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: syn::Path = input.parse()?;
+        if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            let value: LitStr = input.parse()?;
+            Ok(MetadataItem::KeyValue(path, value))
+        } else {
+            Ok(MetadataItem::Flag(path))
+        }
+    }
+}
+
+/// Collects every `#[metadata(...)]` item attached to an item/field into a flat list.
+fn metadata_items(attrs: &[Attribute]) -> syn::Result<Vec<MetadataItem>> {
+    let mut items = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("metadata") {
+            continue;
+        }
+        let parsed = attr.parse_args_with(Punctuated::<MetadataItem, Token![,]>::parse_terminated)?;
+        items.extend(parsed);
+    }
+    Ok(items)
+}
+
+/// Builds the `Option<Deprecation>` expression for an item/field from its standard
+/// `#[deprecated(since = "...", note = "...")]` attribute, if any.
+fn deprecation_expr(attrs: &[Attribute]) -> syn::Result<TokenStream2> {
+    for attr in attrs {
+        if !attr.path().is_ident("deprecated") {
+            continue;
+        }
+        let mut since: Option<LitStr> = None;
+        let mut note: Option<LitStr> = None;
+        if let Meta::List(_) = &attr.meta {
+            let items = attr.parse_args_with(Punctuated::<MetadataItem, Token![,]>::parse_terminated)?;
+            for item in items {
+                match item {
+                    MetadataItem::KeyValue(path, value) if path.is_ident("since") => since = Some(value),
+                    MetadataItem::KeyValue(path, value) if path.is_ident("note") => note = Some(value),
+                    _ => {}
+                }
+            }
+        }
+        let since = opt_str_expr(since);
+        let note = opt_str_expr(note);
+        return Ok(quote! {
+            ::std::option::Option::Some(::struct_metadata::Deprecation { since: #since, note: #note })
+        });
+    }
+    Ok(quote! { ::std::option::Option::None })
+}
+
+/// Builds the `Option<Stability>` expression for an item/field from `#[metadata(stable_since =
+/// "...")]` or `#[metadata(unstable)]`.
+fn stability_expr(items: &[MetadataItem]) -> TokenStream2 {
+    for item in items {
+        match item {
+            MetadataItem::KeyValue(path, value) if path.is_ident("stable_since") => {
+                return quote! {
+                    ::std::option::Option::Some(::struct_metadata::Stability {
+                        level: ::struct_metadata::StabilityLevel::Stable,
+                        since: ::std::option::Option::Some(#value),
+                    })
+                };
+            }
+            MetadataItem::Flag(path) if path.is_ident("unstable") => {
+                return quote! {
+                    ::std::option::Option::Some(::struct_metadata::Stability {
+                        level: ::struct_metadata::StabilityLevel::Unstable,
+                        since: ::std::option::Option::None,
+                    })
+                };
+            }
+            _ => {}
+        }
+    }
+    quote! { ::std::option::Option::None }
+}
+
+/// `true` if any of the collected `#[metadata(...)]` items is the bare flag `name`.
+fn has_flag(items: &[MetadataItem], name: &str) -> bool {
+    items.iter().any(|item| matches!(item, MetadataItem::Flag(path) if path.is_ident(name)))
+}
+
+/// Every `#[metadata(alias = "...")]` value attached to a field.
+fn aliases(items: &[MetadataItem]) -> Vec<&LitStr> {
+    items.iter().filter_map(|item| match item {
+        MetadataItem::KeyValue(path, value) if path.is_ident("alias") => Some(value),
+        _ => None,
+    }).collect()
+}
+
+/// The `#[metadata(key = "value")]` metadata pairs, for building the metadata value (keys reserved for
+/// other purposes are skipped).
+fn metadata_pairs(items: &[MetadataItem]) -> Vec<(&syn::Path, &LitStr)> {
+    const RESERVED: &[&str] = &["default", "alias", "stable_since", "unstable"];
+    items.iter().filter_map(|item| match item {
+        MetadataItem::KeyValue(path, value) if !RESERVED.iter().any(|r| path.is_ident(r)) => Some((path, value)),
+        _ => None,
+    }).collect()
+}
+
+/// Parses the struct-level `#[metadata_type(T)]` attribute, if present, naming the type to use
+/// for `Descriptor::metadata` in place of the default `HashMap<&'static str, &'static str>`.
+fn metadata_type_attr(attrs: &[Attribute]) -> syn::Result<Option<syn::Type>> {
+    for attr in attrs {
+        if attr.path().is_ident("metadata_type") {
+            return Ok(Some(attr.parse_args()?));
+        }
+    }
+    Ok(None)
+}
+
+/// Builds the `metadata` field expression from a set of `key = "value"` pairs.
+///
+/// Without a `metadata_type`, this collects the pairs into a `HashMap<&'static str, &'static
+/// str>` keyed by the stringified attribute key. With one, it assigns each pair onto a
+/// `T::default()` value's same-named field instead, so unset fields keep their `Default`.
+fn metadata_expr(pairs: &[(&syn::Path, &LitStr)], metadata_ty: Option<&syn::Type>) -> TokenStream2 {
+    match metadata_ty {
+        None => {
+            let keys = pairs.iter().map(|(path, _)| quote! { ::std::stringify!(#path) });
+            let values = pairs.iter().map(|(_, value)| value);
+            quote! {
+                {
+                    #[allow(unused_mut)]
+                    let mut map = ::std::collections::HashMap::new();
+                    #(map.insert(#keys, #values);)*
+                    map
+                }
+            }
+        }
+        Some(ty) => {
+            let assignments = pairs.iter().map(|(path, value)| quote! { m.#path = #value; });
+            quote! {
+                {
+                    #[allow(unused_mut)]
+                    let mut m = <#ty as ::std::default::Default>::default();
+                    #(#assignments)*
+                    m
+                }
+            }
+        }
+    }
+}
+
+/// Builds an `Option<Vec<&'static str>>` docs expression from a run of `#[doc = "..."]`
+/// attributes (the desugared form of `///` doc comments), trimming the leading space rustc adds.
+fn docs_expr(attrs: &[Attribute]) -> TokenStream2 {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let Meta::NameValue(nv) = &attr.meta {
+            if let syn::Expr::Lit(expr_lit) = &nv.value {
+                if let syn::Lit::Str(lit) = &expr_lit.lit {
+                    lines.push(lit.value().trim().to_string());
+                }
+            }
+        }
+    }
+    if lines.is_empty() {
+        quote! { ::std::option::Option::None }
+    } else {
+        quote! { ::std::option::Option::Some(::std::vec![#(#lines),*]) }
+    }
+}
+
+/// `Some(#lit)` if `lit` is present, else `None` — both as `Option<&'static str>` token streams.
+fn opt_str_expr(lit: Option<LitStr>) -> TokenStream2 {
+    match lit {
+        Some(lit) => quote! { ::std::option::Option::Some(#lit) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+/// Builds the generated `Described` impl for `input`.
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let struct_name = name.to_string();
+    let docs = docs_expr(&input.attrs);
+    let item_items = metadata_items(&input.attrs)?;
+    let deprecation = deprecation_expr(&input.attrs)?;
+    let stability = stability_expr(&item_items);
+    let item_pairs = metadata_pairs(&item_items);
+
+    let metadata_ty = metadata_type_attr(&input.attrs)?;
+    let metadata = metadata_expr(&item_pairs, metadata_ty.as_ref());
+    let metadata_ty_tokens = match &metadata_ty {
+        Some(ty) => quote! { #ty },
+        None => quote! { ::std::collections::HashMap<&'static str, &'static str> },
+    };
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input, "#[derive(Described)] only supports structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(&input, "#[derive(Described)] requires named fields"));
+    };
+
+    let entries = fields.named.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let label = field_ident.to_string();
+        let ty = &field.ty;
+        let docs = docs_expr(&field.attrs);
+        let items = metadata_items(&field.attrs)?;
+        let deprecation = deprecation_expr(&field.attrs)?;
+        let stability = stability_expr(&items);
+        let has_default = has_flag(&items, "default");
+        let alias_lits = aliases(&items);
+        // `Entry::metadata` is always the default `HashMap`, regardless of the enclosing
+        // struct's `#[metadata_type(T)]` — only `Descriptor::metadata` itself is generic.
+        let metadata = metadata_expr(&metadata_pairs(&items), None);
+
+        Ok(quote! {
+            ::struct_metadata::Entry {
+                label: #label.to_string(),
+                docs: #docs,
+                metadata: #metadata,
+                type_info: <#ty as ::struct_metadata::Described>::metadata(),
+                has_default: #has_default,
+                aliases: &[#(#alias_lits),*],
+                deprecation: #deprecation,
+                stability: #stability,
+            }
+        })
+    }).collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl ::struct_metadata::Described<#metadata_ty_tokens> for #name {
+            fn metadata() -> ::struct_metadata::Descriptor<#metadata_ty_tokens> {
+                ::struct_metadata::Descriptor {
+                    docs: #docs,
+                    metadata: #metadata,
+                    kind: ::struct_metadata::Kind::Struct {
+                        name: #struct_name.to_string(),
+                        children: ::std::vec![#(#entries),*],
+                    },
+                    deprecation: #deprecation,
+                    stability: #stability,
+                }
+            }
+        }
+    })
+}
@@ -7,6 +7,10 @@
 
 pub use struct_metadata_derive::Described;
 
+pub mod serialize;
+#[cfg(feature = "serde")]
+pub mod validate;
+
 use std::collections::HashMap;
 
 
@@ -15,17 +19,50 @@ use std::collections::HashMap;
 pub enum Kind {
     Struct { name: String, children: Vec<Entry>, },
     Aliased { name: String, kind: Box<Descriptor> },
+    Matrix { rows: Option<usize>, cols: Option<usize>, element: Box<Descriptor> },
     Bool,
     U64,
     String,
 }
 
+/// Deprecation information for a [`Descriptor`] or [`Entry`], populated from a standard
+/// `#[deprecated(since = "...", note = "...")]` attribute.
+///
+/// `#[derive(Described)]` reads `#[deprecated]` off the annotated item/field and fills this in
+/// automatically; it can also be supplied by constructing a [`Descriptor`]/[`Entry`] by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    pub since: Option<&'static str>,
+    pub note: Option<&'static str>,
+}
+
+/// How stable a [`Descriptor`] or [`Entry`] is, populated from a `#[metadata(stable_since =
+/// "...")]` or `#[metadata(unstable)]` marker.
+///
+/// Same as [`Deprecation`]: `#[derive(Described)]` reads the marker attribute automatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stability {
+    pub level: StabilityLevel,
+    pub since: Option<&'static str>,
+}
+
+/// The stability level carried by a [`Stability`] value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StabilityLevel {
+    Stable,
+    Unstable,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Entry {
     pub label: String,
     pub docs: Option<Vec<&'static str>>,
     pub metadata: HashMap<&'static str, &'static str>,
     pub type_info: Descriptor,
+    pub has_default: bool,
+    pub aliases: &'static [&'static str],
+    pub deprecation: Option<Deprecation>,
+    pub stability: Option<Stability>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -33,6 +70,8 @@ pub struct Descriptor<Metadata=HashMap<&'static str, &'static str>> {
     pub docs: Option<Vec<&'static str>>,
     pub metadata: Metadata,
     pub kind: Kind,
+    pub deprecation: Option<Deprecation>,
+    pub stability: Option<Stability>,
 }
 
 pub trait Described<M: Default=HashMap<&'static str, &'static str>> {
@@ -41,18 +80,18 @@ pub trait Described<M: Default=HashMap<&'static str, &'static str>> {
 
 impl<M: Default> Described<M> for bool {
     fn metadata() -> Descriptor<M> {
-        Descriptor { docs: None, metadata: Default::default(), kind: Kind::Bool }
+        Descriptor { docs: None, metadata: Default::default(), kind: Kind::Bool, deprecation: None, stability: None }
     }
 }
 
 impl<M: Default> Described<M> for u64 {
     fn metadata() -> Descriptor<M> {
-        Descriptor { docs: None, metadata: Default::default(), kind: Kind::U64 }
+        Descriptor { docs: None, metadata: Default::default(), kind: Kind::U64, deprecation: None, stability: None }
     }
 }
 
 impl<M: Default> Described<M> for String {
     fn metadata() -> Descriptor<M> {
-        Descriptor { docs: None, metadata: Default::default(), kind: Kind::String }
+        Descriptor { docs: None, metadata: Default::default(), kind: Kind::String, deprecation: None, stability: None }
     }
 }
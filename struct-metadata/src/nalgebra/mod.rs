@@ -11,25 +11,46 @@
 //!
 //! Supported Types:
 //! - `Matrix<T, R, C>`: Describes a matrix with rows `R`, columns `C`, and elements of type `T`.
+//!   Compile-time extents are reported via `Kind::Matrix`; dimensions that are only known at
+//!   runtime (e.g. `Dyn`) are reported as `None`. `DVector<T>` and `DMatrix<T>` are covered by
+//!   this same blanket implementation and simply report `None` for their dynamic extents.
 //! - `Vector3<T>`: Describes a 3D vector as a matrix with 3 rows and 1 column.
 //! - `Rotation3<T>`: Describes a 3D rotation as a 3x3 matrix.
-//! - `Isometry3<T>`: Describes an isometry (a combination of rotation and translation) in 3D space, 
+//! - `Isometry3<T>`: Describes an isometry (a combination of rotation and translation) in 3D space,
 //!   with metadata for both the rotation and translation components.
+//! - `Point2<T>`/`Point3<T>`: Describes a point as a struct wrapping a `coords` vector field.
+//! - `Translation3<T>`: Describes a translation as a struct wrapping a `vector` field.
+//! - `Quaternion<T>`/`UnitQuaternion<T>`: Describes a quaternion as a struct with `w`, `i`, `j`,
+//!   and `k` component fields.
+//! - `Complex<T>`: Describes a complex number as a struct with `re` and `im` fields.
 //!
 //! This feature is intended to be used with the `nalgebra` library and can be enabled by
 //! adding the `nalgebra` feature flag to the `struct-metadata` crate.
+//!
+//! Enabling the additional `nalgebra-sparse` feature brings in [`sparse`], which describes
+//! `CsrMatrix`/`CooMatrix` from the `nalgebra-sparse` crate.
+
+#[cfg(feature = "nalgebra-sparse")]
+pub mod sparse;
 
 use crate::{Described, Descriptor, Kind, Entry};
 
-use nalgebra::{ArrayStorage, Const, Isometry3, Scalar, Vector3, Rotation3};
+use nalgebra::{Const, Isometry3, Scalar, Vector2, Vector3, Rotation3};
 use nalgebra::{Matrix, Dim, Storage};
+use nalgebra::{Point2, Point3, Translation3, Quaternion, UnitQuaternion, Complex};
 
 impl<M: Default, T: Described<M> + Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> crate::Described<M> for Matrix<T, R, C, S> {
     fn metadata() -> Descriptor<M> {
         Descriptor {
             docs: Some(vec!["A matrix from nalgebra"]),
             metadata: M::default(),
-            kind: Kind::Sequence(Box::new(T::metadata())),  // Each element is of type T, described by T::metadata()
+            deprecation: None,
+            stability: None,
+            kind: Kind::Matrix {
+                rows: R::try_to_usize(),
+                cols: C::try_to_usize(),
+                element: Box::new(T::metadata()), // Each element is of type T, described by T::metadata()
+            },
         }
     }
 }
@@ -39,7 +60,13 @@ impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Rotation3<T>
         Descriptor {
             docs: Some(vec!["A 3D rotation matrix from nalgebra"]),
             metadata: M::default(),
-            kind: Kind::Sequence(Box::new(Matrix::<T, Const<3>, Const<3>, ArrayStorage<T, 3, 3>>::metadata())), // Describe as a 3x3 matrix
+            deprecation: None,
+            stability: None,
+            kind: Kind::Matrix {
+                rows: Const::<3>::try_to_usize(),
+                cols: Const::<3>::try_to_usize(),
+                element: Box::new(T::metadata()),
+            },
         }
     }
 }
@@ -49,6 +76,8 @@ impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Isometry3<T>
         Descriptor {
             docs: Some(vec!["A 3D isometry from nalgebra"]),
             metadata: M::default(),
+            deprecation: None,
+            stability: None,
             kind: Kind::Struct {
                 name: "Isometry3",
                 children: vec![
@@ -56,6 +85,8 @@ impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Isometry3<T>
                         label: "rotation",
                         docs: Some(vec!["The rotation component of the isometry"]),
                         metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
                         type_info: Rotation3::<T>::metadata(),// Rotation is a 3x3 matrix
                         has_default: false,
                         aliases: &[],
@@ -64,6 +95,8 @@ impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Isometry3<T>
                         label: "translation",
                         docs: Some(vec!["The translation component of the isometry"]),
                         metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
                         type_info: Vector3::<T>::metadata(), // Translation is a 3D vector
                         has_default: false,
                         aliases: &[],
@@ -73,3 +106,185 @@ impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Isometry3<T>
         }
     }
 }
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Point2<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A 2D point from nalgebra"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "Point2",
+                children: vec![
+                    Entry {
+                        label: "coords",
+                        docs: Some(vec!["The coordinates of the point"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: Vector2::<T>::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Point3<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A 3D point from nalgebra"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "Point3",
+                children: vec![
+                    Entry {
+                        label: "coords",
+                        docs: Some(vec!["The coordinates of the point"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: Vector3::<T>::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Translation3<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A 3D translation from nalgebra"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "Translation3",
+                children: vec![
+                    Entry {
+                        label: "vector",
+                        docs: Some(vec!["The translation vector"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: Vector3::<T>::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Quaternion<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A quaternion from nalgebra"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "Quaternion",
+                children: vec![
+                    Entry {
+                        label: "w",
+                        docs: Some(vec!["The real (scalar) component of the quaternion"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "i",
+                        docs: Some(vec!["The i imaginary component of the quaternion"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "j",
+                        docs: Some(vec!["The j imaginary component of the quaternion"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "k",
+                        docs: Some(vec!["The k imaginary component of the quaternion"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for UnitQuaternion<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A unit (normalized) quaternion from nalgebra, representing a rotation"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Quaternion::<T>::metadata().kind, // Same shape as a plain quaternion
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for Complex<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A complex number from nalgebra"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "Complex",
+                children: vec![
+                    Entry {
+                        label: "re",
+                        docs: Some(vec!["The real part of the complex number"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "im",
+                        docs: Some(vec!["The imaginary part of the complex number"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Metadata for `nalgebra-sparse` matrix formats.
+//!
+//! This submodule is enabled by the `nalgebra-sparse` feature. It describes the sparse
+//! matrix types as a struct carrying their logical shape (`nrows`, `ncols`) alongside a
+//! descriptor for the type of the stored values, mirroring how the dense `Matrix` impl in
+//! the parent module describes shape.
+
+use crate::{Described, Descriptor, Kind, Entry};
+
+use nalgebra::Scalar;
+use nalgebra_sparse::{CsrMatrix, CooMatrix};
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for CsrMatrix<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A sparse matrix from nalgebra-sparse, stored in compressed sparse row (CSR) format"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "CsrMatrix",
+                children: vec![
+                    Entry {
+                        label: "nrows",
+                        docs: Some(vec!["The number of rows in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: u64::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "ncols",
+                        docs: Some(vec!["The number of columns in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: u64::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "values",
+                        docs: Some(vec!["The non-zero values stored in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl<M: Default, T: Described<M> + Scalar> crate::Described<M> for CooMatrix<T> {
+    fn metadata() -> Descriptor<M> {
+        Descriptor {
+            docs: Some(vec!["A sparse matrix from nalgebra-sparse, stored in coordinate (COO) format"]),
+            metadata: M::default(),
+            deprecation: None,
+            stability: None,
+            kind: Kind::Struct {
+                name: "CooMatrix",
+                children: vec![
+                    Entry {
+                        label: "nrows",
+                        docs: Some(vec!["The number of rows in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: u64::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "ncols",
+                        docs: Some(vec!["The number of columns in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: u64::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                    Entry {
+                        label: "values",
+                        docs: Some(vec!["The non-zero values stored in the matrix"]),
+                        metadata: M::default(),
+                        deprecation: None,
+                        stability: None,
+                        type_info: T::metadata(),
+                        has_default: false,
+                        aliases: &[],
+                    },
+                ],
+            },
+        }
+    }
+}
@@ -0,0 +1,687 @@
+//! Lazy, positionally-addressable binary encoding of a [`Descriptor`] tree.
+//!
+//! This mirrors rustc's lazy-metadata scheme: [`write`] serializes a `Descriptor` into a
+//! single self-contained byte blob, but whenever one node references another (an
+//! [`Entry::type_info`], or the boxed inner `Kind` of `Aliased`/`Matrix`) the child is not
+//! inlined. Instead the child is encoded first, and the parent stores a [`Lazy`] handle: a
+//! varint-encoded relative distance from the position just past the parent's fixed-size
+//! header (`min_end`) back to the child's absolute position. [`Reader`] can then decode a
+//! single node at a time, following `Lazy` handles only for the fields actually needed,
+//! which makes reading one nested field of a large tree O(depth) instead of O(size).
+//!
+//! `Kind` never varies with the root `Descriptor`'s metadata type (nested `Entry`/`Descriptor`
+//! values are always the default `HashMap<&'static str, &'static str>`), so only the root's own
+//! `metadata` field needs a pluggable encoding: [`write`]/[`Reader::read_full`] handle that
+//! default case, and, behind the `serde` feature, [`write_with_metadata`]/
+//! [`Reader::read_full_with_metadata`] handle an arbitrary `Serialize`/`DeserializeOwned` `M`
+//! by storing its `metadata` as a length-prefixed JSON payload instead.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::{Deprecation, Descriptor, Entry, Kind, Stability, StabilityLevel};
+
+/// A handle to a value encoded elsewhere in the same blob.
+///
+/// The value itself is not decoded until [`Reader::read`] (or [`Reader::read_entry`]) is
+/// called with this handle, which is what gives the format its "lazy" random access.
+///
+/// `Lazy<T>` only ever stores a byte position, so it implements `Clone`/`Copy`/`Debug`/
+/// `PartialEq`/`Eq` unconditionally rather than deriving them, which would otherwise
+/// require `T` to implement those traits too.
+pub struct Lazy<T> {
+    /// Absolute byte position of the referenced value within the blob.
+    position: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Lazy<T> {
+    fn new(position: u64) -> Self {
+        Lazy { position, _marker: PhantomData }
+    }
+
+    /// The absolute byte position this handle refers to, for use with [`Reader::read`].
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Lazy<T> {}
+
+impl<T> std::fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lazy").field("position", &self.position).finish()
+    }
+}
+
+impl<T> PartialEq for Lazy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+    }
+}
+
+impl<T> Eq for Lazy<T> {}
+
+const TAG_STRUCT: u8 = 0;
+const TAG_ALIASED: u8 = 1;
+const TAG_MATRIX: u8 = 2;
+const TAG_BOOL: u8 = 3;
+const TAG_U64: u8 = 4;
+const TAG_STRING: u8 = 5;
+
+/// Encode `descriptor` into a self-contained byte blob.
+///
+/// Returns the blob along with the absolute position of the root node within it, which is
+/// the offset to pass to [`Reader::read`].
+pub fn write(descriptor: &Descriptor) -> (Vec<u8>, u64) {
+    let mut buf = Vec::new();
+    let root = write_descriptor(&mut buf, descriptor);
+    (buf, root)
+}
+
+/// Encode `descriptor` the same way as [`write`], but for a `Descriptor<M>` whose metadata is
+/// an arbitrary `Serialize`-able `M` rather than the default `HashMap`. `M` is stored as a
+/// length-prefixed JSON payload; everything else (including every nested `Entry`/`Descriptor`,
+/// which remain the default `HashMap`) uses the identical binary encoding.
+#[cfg(feature = "serde")]
+pub fn write_with_metadata<M: serde::Serialize>(descriptor: &Descriptor<M>) -> (Vec<u8>, u64) {
+    let mut buf = Vec::new();
+    let root = write_node(&mut buf, &descriptor.kind, &descriptor.docs, &descriptor.deprecation, &descriptor.stability, |buf| {
+        write_metadata_json(buf, &descriptor.metadata)
+    });
+    (buf, root)
+}
+
+// Nodes are written children-first (post-order): by the time a node's own fixed header is
+// appended, every child it references has already been written earlier in `buf`, so its
+// `Lazy` distances (child_pos - min_end) are always negative and are varint-encoded with
+// zigzag so that sign costs nothing extra for the (far more common) non-lazy fields.
+
+fn write_descriptor(buf: &mut Vec<u8>, descriptor: &Descriptor) -> u64 {
+    write_node(buf, &descriptor.kind, &descriptor.docs, &descriptor.deprecation, &descriptor.stability, |buf| {
+        write_metadata(buf, &descriptor.metadata)
+    })
+}
+
+// Shared by `write_descriptor` and `write_with_metadata`: `Kind` and its nested
+// `Entry`/`Descriptor` values never depend on the root's metadata type, so only the metadata
+// field itself needs to be pluggable — `write_metadata_field` is called exactly once, at the
+// point in the node's fixed header where the encoded metadata type requires.
+fn write_node(
+    buf: &mut Vec<u8>,
+    kind: &Kind,
+    docs: &Option<Vec<&'static str>>,
+    deprecation: &Option<Deprecation>,
+    stability: &Option<Stability>,
+    write_metadata_field: impl FnOnce(&mut Vec<u8>),
+) -> u64 {
+    match kind {
+        Kind::Aliased { name, kind } => {
+            let inner = write_descriptor(buf, kind);
+            let start = buf.len() as u64;
+            write_opt_strs(buf, docs);
+            write_metadata_field(buf);
+            write_deprecation(buf, deprecation);
+            write_stability(buf, stability);
+            buf.push(TAG_ALIASED);
+            write_string(buf, name);
+            let min_end = buf.len() as u64;
+            write_lazy_distance(buf, inner, min_end);
+            start
+        }
+        Kind::Matrix { rows, cols, element } => {
+            let inner = write_descriptor(buf, element);
+            let start = buf.len() as u64;
+            write_opt_strs(buf, docs);
+            write_metadata_field(buf);
+            write_deprecation(buf, deprecation);
+            write_stability(buf, stability);
+            buf.push(TAG_MATRIX);
+            write_opt_usize(buf, *rows);
+            write_opt_usize(buf, *cols);
+            let min_end = buf.len() as u64;
+            write_lazy_distance(buf, inner, min_end);
+            start
+        }
+        Kind::Struct { name, children } => {
+            let entry_positions: Vec<u64> = children.iter().map(|e| write_entry(buf, e)).collect();
+            let start = buf.len() as u64;
+            write_opt_strs(buf, docs);
+            write_metadata_field(buf);
+            write_deprecation(buf, deprecation);
+            write_stability(buf, stability);
+            buf.push(TAG_STRUCT);
+            write_string(buf, name);
+            write_varint(buf, entry_positions.len() as u64);
+            let min_end = buf.len() as u64;
+            for pos in entry_positions {
+                write_lazy_distance(buf, pos, min_end);
+            }
+            start
+        }
+        Kind::Bool | Kind::U64 | Kind::String => {
+            let start = buf.len() as u64;
+            write_opt_strs(buf, docs);
+            write_metadata_field(buf);
+            write_deprecation(buf, deprecation);
+            write_stability(buf, stability);
+            buf.push(match kind {
+                Kind::Bool => TAG_BOOL,
+                Kind::U64 => TAG_U64,
+                Kind::String => TAG_STRING,
+                _ => unreachable!(),
+            });
+            start
+        }
+    }
+}
+
+/// Encode `metadata` as a length-prefixed JSON payload, for [`write_with_metadata`].
+#[cfg(feature = "serde")]
+fn write_metadata_json<M: serde::Serialize>(buf: &mut Vec<u8>, metadata: &M) {
+    let bytes = serde_json::to_vec(metadata).expect("struct-metadata metadata failed to serialize to JSON");
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(&bytes);
+}
+
+// An `Entry`'s fixed fields (label, docs, metadata, has_default, aliases) are written
+// inline; only its `type_info` subtree is deferred behind a `Lazy` handle, for the same
+// reason a `Descriptor`'s boxed children are.
+fn write_entry(buf: &mut Vec<u8>, entry: &Entry) -> u64 {
+    let inner = write_descriptor(buf, &entry.type_info);
+    let start = buf.len() as u64;
+    write_string(buf, &entry.label);
+    write_opt_strs(buf, &entry.docs);
+    write_metadata(buf, &entry.metadata);
+    write_deprecation(buf, &entry.deprecation);
+    write_stability(buf, &entry.stability);
+    buf.push(entry.has_default as u8);
+    write_varint(buf, entry.aliases.len() as u64);
+    for alias in entry.aliases {
+        write_string(buf, alias);
+    }
+    let min_end = buf.len() as u64;
+    write_lazy_distance(buf, inner, min_end);
+    start
+}
+
+fn write_lazy_distance(buf: &mut Vec<u8>, child_pos: u64, min_end: u64) {
+    write_signed_varint(buf, child_pos as i64 - min_end as i64);
+}
+
+fn write_opt_usize(buf: &mut Vec<u8>, value: Option<usize>) {
+    match value {
+        None => buf.push(0),
+        Some(v) => {
+            buf.push(1);
+            write_varint(buf, v as u64);
+        }
+    }
+}
+
+fn write_opt_strs(buf: &mut Vec<u8>, value: &Option<Vec<&'static str>>) {
+    match value {
+        None => buf.push(0),
+        Some(strs) => {
+            buf.push(1);
+            write_varint(buf, strs.len() as u64);
+            for s in strs {
+                write_string(buf, s);
+            }
+        }
+    }
+}
+
+fn write_opt_str(buf: &mut Vec<u8>, value: Option<&'static str>) {
+    match value {
+        None => buf.push(0),
+        Some(s) => {
+            buf.push(1);
+            write_string(buf, s);
+        }
+    }
+}
+
+fn write_deprecation(buf: &mut Vec<u8>, value: &Option<Deprecation>) {
+    match value {
+        None => buf.push(0),
+        Some(dep) => {
+            buf.push(1);
+            write_opt_str(buf, dep.since);
+            write_opt_str(buf, dep.note);
+        }
+    }
+}
+
+fn write_stability(buf: &mut Vec<u8>, value: &Option<Stability>) {
+    match value {
+        None => buf.push(0),
+        Some(stability) => {
+            buf.push(1);
+            buf.push(match stability.level {
+                StabilityLevel::Stable => 0,
+                StabilityLevel::Unstable => 1,
+            });
+            write_opt_str(buf, stability.since);
+        }
+    }
+}
+
+fn write_metadata(buf: &mut Vec<u8>, metadata: &HashMap<&'static str, &'static str>) {
+    write_varint(buf, metadata.len() as u64);
+    for (key, value) in metadata {
+        write_string(buf, key);
+        write_string(buf, value);
+    }
+}
+
+fn write_string(buf: &mut Vec<u8>, s: &str) {
+    write_varint(buf, s.len() as u64);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            break;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn write_signed_varint(buf: &mut Vec<u8>, value: i64) {
+    write_varint(buf, zigzag_encode(value));
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// One [`Descriptor`] decoded from a blob, with nested subtrees left as [`Lazy`] handles.
+///
+/// Fields that [`Descriptor`] stores as `&'static str` are decoded as owned [`String`]s here
+/// instead, so reading a node never leaks memory — see [`Reader::read`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedDescriptor {
+    /// See [`Descriptor::docs`].
+    pub docs: Option<Vec<String>>,
+    /// See [`Descriptor::metadata`].
+    pub metadata: HashMap<String, String>,
+    /// See [`Descriptor::kind`], with boxed children left undecoded.
+    pub kind: DecodedKind,
+    /// See [`Descriptor::deprecation`].
+    pub deprecation: Option<DecodedDeprecation>,
+    /// See [`Descriptor::stability`].
+    pub stability: Option<DecodedStability>,
+}
+
+/// The [`Kind`] of a [`DecodedDescriptor`], with nested descriptors and entries left as
+/// [`Lazy`] handles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedKind {
+    /// See [`Kind::Struct`]. Each child is an unresolved handle rather than a decoded
+    /// [`DecodedEntry`]: resolving one does not require decoding the others' headers.
+    Struct { name: String, children: Vec<Lazy<Entry>> },
+    /// See [`Kind::Aliased`].
+    Aliased { name: String, kind: Lazy<Descriptor> },
+    /// See [`Kind::Matrix`].
+    Matrix { rows: Option<usize>, cols: Option<usize>, element: Lazy<Descriptor> },
+    /// See [`Kind::Bool`].
+    Bool,
+    /// See [`Kind::U64`].
+    U64,
+    /// See [`Kind::String`].
+    String,
+}
+
+/// An [`Entry`] decoded from a blob, with its `type_info` left as a [`Lazy`] handle.
+///
+/// Same owned-string rationale as [`DecodedDescriptor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEntry {
+    /// See [`Entry::label`].
+    pub label: String,
+    /// See [`Entry::docs`].
+    pub docs: Option<Vec<String>>,
+    /// See [`Entry::metadata`].
+    pub metadata: HashMap<String, String>,
+    /// See [`Entry::type_info`].
+    pub type_info: Lazy<Descriptor>,
+    /// See [`Entry::has_default`].
+    pub has_default: bool,
+    /// See [`Entry::aliases`].
+    pub aliases: Vec<String>,
+    /// See [`Entry::deprecation`].
+    pub deprecation: Option<DecodedDeprecation>,
+    /// See [`Entry::stability`].
+    pub stability: Option<DecodedStability>,
+}
+
+/// An owned-string counterpart of [`Deprecation`], for the non-leaking decode path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedDeprecation {
+    /// See [`Deprecation::since`].
+    pub since: Option<String>,
+    /// See [`Deprecation::note`].
+    pub note: Option<String>,
+}
+
+/// An owned-string counterpart of [`Stability`], for the non-leaking decode path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedStability {
+    /// See [`Stability::level`].
+    pub level: StabilityLevel,
+    /// See [`Stability::since`].
+    pub since: Option<String>,
+}
+
+/// Decodes nodes out of a blob produced by [`write`], one at a time.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Wrap a blob produced by [`write`] for decoding.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    /// Decode exactly the [`DecodedDescriptor`] at `position`, leaving any nested
+    /// descriptors (boxed `Kind` children, `Entry::type_info`) as unresolved [`Lazy`]
+    /// handles. Struct children are returned as `Lazy<Entry>` positions rather than decoded
+    /// entries, so this never decodes more than the one node at `position` — no sibling
+    /// headers, and no heap-leaking, regardless of how many times it's called.
+    pub fn read(&self, position: u64) -> DecodedDescriptor {
+        let mut pos = position as usize;
+        let docs = self.read_opt_strs(&mut pos);
+        let metadata = self.read_metadata(&mut pos);
+        let deprecation = self.read_deprecation(&mut pos);
+        let stability = self.read_stability(&mut pos);
+        let kind = self.read_kind(&mut pos);
+        DecodedDescriptor { docs, metadata, kind, deprecation, stability }
+    }
+
+    /// Decode exactly the [`Descriptor`] at `position`, the same way as [`Reader::read_full`],
+    /// but for a `Descriptor<M>` whose metadata was encoded by [`write_with_metadata`] as JSON
+    /// rather than the default `HashMap`.
+    #[cfg(feature = "serde")]
+    pub fn read_full_with_metadata<M: serde::de::DeserializeOwned>(&self, position: u64) -> Descriptor<M> {
+        let mut pos = position as usize;
+        let docs = self.read_opt_strs(&mut pos);
+        let metadata = self.read_metadata_json(&mut pos);
+        let deprecation = self.read_deprecation(&mut pos);
+        let stability = self.read_stability(&mut pos);
+        let decoded_kind = self.read_kind(&mut pos);
+        Descriptor {
+            docs: docs.map(leak_strs),
+            metadata,
+            kind: self.resolve_kind(decoded_kind),
+            deprecation: deprecation.map(leak_deprecation),
+            stability: stability.map(leak_stability),
+        }
+    }
+
+    // Shared by `read`/`read_full_with_metadata`: decodes the `Kind` tag and whatever follows
+    // it, which never depends on the root descriptor's metadata type (the docs/metadata/
+    // deprecation/stability fields before it do, and are read by the caller).
+    fn read_kind(&self, pos: &mut usize) -> DecodedKind {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        match tag {
+            TAG_BOOL => DecodedKind::Bool,
+            TAG_U64 => DecodedKind::U64,
+            TAG_STRING => DecodedKind::String,
+            TAG_ALIASED => {
+                let name = self.read_string(pos);
+                let min_end = *pos as u64;
+                let kind = Lazy::new(self.read_lazy_position(pos, min_end));
+                DecodedKind::Aliased { name, kind }
+            }
+            TAG_MATRIX => {
+                let rows = self.read_opt_usize(pos);
+                let cols = self.read_opt_usize(pos);
+                let min_end = *pos as u64;
+                let element = Lazy::new(self.read_lazy_position(pos, min_end));
+                DecodedKind::Matrix { rows, cols, element }
+            }
+            TAG_STRUCT => {
+                let name = self.read_string(pos);
+                let count = self.read_varint(pos);
+                let min_end = *pos as u64;
+                let mut children = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    children.push(Lazy::new(self.read_lazy_position(pos, min_end)));
+                }
+                DecodedKind::Struct { name, children }
+            }
+            other => panic!("unknown Kind tag {other} in struct-metadata blob"),
+        }
+    }
+
+    /// Decode the [`DecodedEntry`] at `position`.
+    pub fn read_entry(&self, position: u64) -> DecodedEntry {
+        let mut pos = position as usize;
+        let label = self.read_string(&mut pos);
+        let docs = self.read_opt_strs(&mut pos);
+        let metadata = self.read_metadata(&mut pos);
+        let deprecation = self.read_deprecation(&mut pos);
+        let stability = self.read_stability(&mut pos);
+        let has_default = self.bytes[pos] != 0;
+        pos += 1;
+        let alias_count = self.read_varint(&mut pos);
+        let mut aliases = Vec::with_capacity(alias_count as usize);
+        for _ in 0..alias_count {
+            aliases.push(self.read_string(&mut pos));
+        }
+        let min_end = pos as u64;
+        let type_info = Lazy::new(self.read_lazy_position(&mut pos, min_end));
+        DecodedEntry { label, docs, metadata, type_info, has_default, aliases, deprecation, stability }
+    }
+
+    /// Fully decode the `Descriptor` rooted at `position`, resolving every [`Lazy`] handle
+    /// it transitively references. Round-trips to an owned [`Descriptor`] equal to the one
+    /// originally passed to [`write`].
+    ///
+    /// Unlike [`Reader::read`]/[`Reader::read_entry`], this does leak: it materializes a real
+    /// [`Descriptor`]/[`Entry`] tree, whose `&'static str` fields have nowhere else to borrow
+    /// from. The leak is bounded by the size of the subtree actually resolved here, not by how
+    /// many times the `Reader` is used — prefer `read`/`read_entry` for repeated partial access.
+    pub fn read_full(&self, position: u64) -> Descriptor {
+        let decoded = self.read(position);
+        Descriptor {
+            docs: decoded.docs.map(leak_strs),
+            metadata: leak_map(decoded.metadata),
+            kind: self.resolve_kind(decoded.kind),
+            deprecation: decoded.deprecation.map(leak_deprecation),
+            stability: decoded.stability.map(leak_stability),
+        }
+    }
+
+    // Shared by `read_full`/`read_full_with_metadata`: resolving a `DecodedKind`'s `Lazy`
+    // handles recurses into `read_full`/`read_entry_full`, which is correct regardless of the
+    // root's metadata type since nested descriptors and entries are always the default
+    // `HashMap`.
+    fn resolve_kind(&self, kind: DecodedKind) -> Kind {
+        match kind {
+            DecodedKind::Bool => Kind::Bool,
+            DecodedKind::U64 => Kind::U64,
+            DecodedKind::String => Kind::String,
+            DecodedKind::Aliased { name, kind } => {
+                Kind::Aliased { name, kind: Box::new(self.read_full(kind.position)) }
+            }
+            DecodedKind::Matrix { rows, cols, element } => {
+                Kind::Matrix { rows, cols, element: Box::new(self.read_full(element.position)) }
+            }
+            DecodedKind::Struct { name, children } => Kind::Struct {
+                name,
+                children: children.into_iter().map(|lazy| self.read_entry_full(lazy.position())).collect(),
+            },
+        }
+    }
+
+    fn read_entry_full(&self, position: u64) -> Entry {
+        let entry = self.read_entry(position);
+        Entry {
+            label: entry.label,
+            docs: entry.docs.map(leak_strs),
+            metadata: leak_map(entry.metadata),
+            type_info: self.read_full(entry.type_info.position),
+            has_default: entry.has_default,
+            aliases: leak_slice(entry.aliases),
+            deprecation: entry.deprecation.map(leak_deprecation),
+            stability: entry.stability.map(leak_stability),
+        }
+    }
+
+    fn read_lazy_position(&self, pos: &mut usize, min_end: u64) -> u64 {
+        let distance = zigzag_decode(self.read_varint(pos));
+        (min_end as i64 + distance) as u64
+    }
+
+    fn read_opt_usize(&self, pos: &mut usize) -> Option<usize> {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        if tag == 0 {
+            None
+        } else {
+            Some(self.read_varint(pos) as usize)
+        }
+    }
+
+    fn read_opt_strs(&self, pos: &mut usize) -> Option<Vec<String>> {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        if tag == 0 {
+            return None;
+        }
+        let count = self.read_varint(pos);
+        let mut strs = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            strs.push(self.read_string(pos));
+        }
+        Some(strs)
+    }
+
+    fn read_opt_str(&self, pos: &mut usize) -> Option<String> {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        if tag == 0 {
+            None
+        } else {
+            Some(self.read_string(pos))
+        }
+    }
+
+    fn read_deprecation(&self, pos: &mut usize) -> Option<DecodedDeprecation> {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        if tag == 0 {
+            return None;
+        }
+        let since = self.read_opt_str(pos);
+        let note = self.read_opt_str(pos);
+        Some(DecodedDeprecation { since, note })
+    }
+
+    fn read_stability(&self, pos: &mut usize) -> Option<DecodedStability> {
+        let tag = self.bytes[*pos];
+        *pos += 1;
+        if tag == 0 {
+            return None;
+        }
+        let level = match self.bytes[*pos] {
+            0 => StabilityLevel::Stable,
+            1 => StabilityLevel::Unstable,
+            other => panic!("unknown StabilityLevel tag {other} in struct-metadata blob"),
+        };
+        *pos += 1;
+        let since = self.read_opt_str(pos);
+        Some(DecodedStability { level, since })
+    }
+
+    fn read_metadata(&self, pos: &mut usize) -> HashMap<String, String> {
+        let count = self.read_varint(pos);
+        let mut map = HashMap::with_capacity(count as usize);
+        for _ in 0..count {
+            let key = self.read_string(pos);
+            let value = self.read_string(pos);
+            map.insert(key, value);
+        }
+        map
+    }
+
+    /// Decode a length-prefixed JSON metadata payload written by [`write_metadata_json`].
+    #[cfg(feature = "serde")]
+    fn read_metadata_json<M: serde::de::DeserializeOwned>(&self, pos: &mut usize) -> M {
+        let len = self.read_varint(pos) as usize;
+        let value = serde_json::from_slice(&self.bytes[*pos..*pos + len])
+            .expect("struct-metadata blob contained JSON that didn't match the requested metadata type");
+        *pos += len;
+        value
+    }
+
+    fn read_string(&self, pos: &mut usize) -> String {
+        let len = self.read_varint(pos) as usize;
+        let s = std::str::from_utf8(&self.bytes[*pos..*pos + len])
+            .expect("struct-metadata blob contained invalid utf-8")
+            .to_string();
+        *pos += len;
+        s
+    }
+
+    fn read_varint(&self, pos: &mut usize) -> u64 {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.bytes[*pos];
+            *pos += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        value
+    }
+}
+
+// The helpers below back [`Reader::read_full`]/[`Reader::read_entry_full`] only: they leak
+// owned strings to satisfy `Descriptor`/`Entry`'s `&'static str` fields, once per resolved
+// node, rather than on every `Reader::read`/`Reader::read_entry` call.
+
+fn leak_str(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+fn leak_strs(strs: Vec<String>) -> Vec<&'static str> {
+    strs.into_iter().map(leak_str).collect()
+}
+
+fn leak_slice(strs: Vec<String>) -> &'static [&'static str] {
+    Box::leak(leak_strs(strs).into_boxed_slice())
+}
+
+fn leak_map(map: HashMap<String, String>) -> HashMap<&'static str, &'static str> {
+    map.into_iter().map(|(k, v)| (leak_str(k), leak_str(v))).collect()
+}
+
+fn leak_deprecation(dep: DecodedDeprecation) -> Deprecation {
+    Deprecation { since: dep.since.map(leak_str), note: dep.note.map(leak_str) }
+}
+
+fn leak_stability(stability: DecodedStability) -> Stability {
+    Stability { level: stability.level, since: stability.since.map(leak_str) }
+}
@@ -0,0 +1,119 @@
+//! Structural validation of decoded JSON data against a [`Descriptor`].
+//!
+//! Enabled by the `serde` feature. [`validate`] walks a [`Descriptor`] alongside a
+//! [`serde_json::Value`] and reports every place the two disagree: fields the descriptor
+//! requires but the value is missing, fields the value has that the descriptor doesn't know
+//! about, and scalar values whose JSON type doesn't match the descriptor's `Kind`.
+
+use serde_json::Value;
+
+use crate::{Descriptor, Entry, Kind};
+
+/// One place a [`serde_json::Value`] failed to match a [`Descriptor`].
+///
+/// `path` holds the chain of field labels from the root down to the offending value, so a
+/// user can be pointed at exactly where the mismatch occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A struct field with no default was required by the descriptor but absent from the
+    /// JSON object.
+    MissingField {
+        /// Labels of the enclosing fields, outermost first.
+        path: Vec<String>,
+        /// The missing field's label.
+        label: String,
+    },
+    /// A JSON object key did not match any field label or alias in the descriptor.
+    UnknownField {
+        /// Labels of the enclosing fields, outermost first.
+        path: Vec<String>,
+        /// The unrecognized object key.
+        key: String,
+    },
+    /// A value's JSON type did not match the descriptor's `Kind`: a scalar of the wrong type,
+    /// or a non-object where a struct is described.
+    TypeMismatch {
+        /// Labels of the enclosing fields, outermost first.
+        path: Vec<String>,
+        /// The type the descriptor's `Kind` requires.
+        expected: &'static str,
+        /// The JSON type actually found.
+        found: &'static str,
+    },
+}
+
+/// Validate `value` against `descriptor`, returning every mismatch found.
+///
+/// An empty result means `value` could be deserialized into the shape `descriptor`
+/// describes without missing, unknown, or mistyped fields. Values described by a
+/// `Kind::Matrix` are not checked.
+pub fn validate<M>(descriptor: &Descriptor<M>, value: &Value) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    validate_at(descriptor, value, &mut Vec::new(), &mut errors);
+    errors
+}
+
+fn validate_at<M>(descriptor: &Descriptor<M>, value: &Value, path: &mut Vec<String>, errors: &mut Vec<ValidationError>) {
+    match &descriptor.kind {
+        Kind::Struct { children, .. } => validate_struct(children, value, path, errors),
+        Kind::Aliased { kind, .. } => validate_at(kind, value, path, errors),
+        Kind::Bool => check_scalar(value, Value::is_boolean, "bool", path, errors),
+        Kind::U64 => check_scalar(value, Value::is_u64, "u64", path, errors),
+        Kind::String => check_scalar(value, Value::is_string, "string", path, errors),
+        // Shape validation for matrices isn't covered by this pass; any value is accepted
+        // as-is, whether or not it is an array.
+        Kind::Matrix { .. } => {}
+    }
+}
+
+fn validate_struct(children: &[Entry], value: &Value, path: &mut Vec<String>, errors: &mut Vec<ValidationError>) {
+    let Some(object) = value.as_object() else {
+        errors.push(ValidationError::TypeMismatch {
+            path: path.clone(),
+            expected: "object",
+            found: json_type_name(value),
+        });
+        return;
+    };
+
+    for entry in children {
+        match find_field(object, entry) {
+            Some(field_value) => {
+                path.push(entry.label.clone());
+                validate_at(&entry.type_info, field_value, path, errors);
+                path.pop();
+            }
+            None if !entry.has_default => {
+                errors.push(ValidationError::MissingField { path: path.clone(), label: entry.label.clone() });
+            }
+            None => {}
+        }
+    }
+
+    for key in object.keys() {
+        if !children.iter().any(|entry| entry.label == *key || entry.aliases.contains(&key.as_str())) {
+            errors.push(ValidationError::UnknownField { path: path.clone(), key: key.clone() });
+        }
+    }
+}
+
+fn find_field<'v>(object: &'v serde_json::Map<String, Value>, entry: &Entry) -> Option<&'v Value> {
+    object.get(&entry.label).or_else(|| entry.aliases.iter().find_map(|alias| object.get(*alias)))
+}
+
+fn check_scalar(value: &Value, matches: fn(&Value) -> bool, expected: &'static str, path: &mut Vec<String>, errors: &mut Vec<ValidationError>) {
+    if !matches(value) {
+        errors.push(ValidationError::TypeMismatch { path: path.clone(), expected, found: json_type_name(value) });
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
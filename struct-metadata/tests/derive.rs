@@ -0,0 +1,26 @@
+#![cfg(test)]
+
+use struct_metadata::{Described, Descriptor, Kind};
+
+/// Exercises `#[derive(Described)]` with a custom `#[metadata(key = "value")]` pair and no
+/// `#[metadata_type]`, which goes through the default `HashMap<&'static str, &'static str>`
+/// path rather than the `metadata_type` struct-field path covered in `tests/nalgebra_.rs`.
+#[derive(Debug, PartialEq, Described)]
+#[metadata(display_name = "Widget")]
+pub struct Widget {
+    /// The widget's name
+    #[metadata(display_name = "Name")]
+    name: String,
+}
+
+#[test]
+fn hash_map_metadata_with_custom_keys() {
+    let data: Descriptor = Widget::metadata();
+    assert_eq!(data.metadata.get("display_name"), Some(&"Widget"));
+
+    let Kind::Struct { children, .. } = data.kind else {
+        panic!("Expected Widget to be described as a struct");
+    };
+    let name_entry = children.iter().find(|e| e.label == "name").expect("name field not found");
+    assert_eq!(name_entry.metadata.get("display_name"), Some(&"Name"));
+}
@@ -3,16 +3,20 @@
 
 use struct_metadata::{Described, Descriptor, Kind, MetadataKind};
 use nalgebra::{Matrix3, Vector3, Rotation3, Isometry3};
+use nalgebra::{Point2, Point3, Translation3, Quaternion, UnitQuaternion, Complex};
+use nalgebra::{DVector, DMatrix};
 
 #[test]
 fn matrix3_metadata() {
     let data: Descriptor<()> = Matrix3::<f32>::metadata();
     assert_eq!(data.docs, Some(vec!["A matrix from nalgebra"]));
     match data.kind {
-        Kind::Sequence(ref boxed) => {
-            assert!(matches!(boxed.kind, Kind::F32));
+        Kind::Matrix { rows, cols, ref element } => {
+            assert_eq!(rows, Some(3));
+            assert_eq!(cols, Some(3));
+            assert!(matches!(element.kind, Kind::F32));
         },
-        _ => panic!("Expected Matrix3 to be described as a sequence"),
+        _ => panic!("Expected Matrix3 to be described as a matrix"),
     }
 }
 
@@ -22,10 +26,40 @@ fn vector3_metadata() {
     let data: Descriptor<()> = Vector3::<f32>::metadata();
     assert_eq!(data.docs, Some(vec!["A matrix from nalgebra"]));
     match data.kind {
-        Kind::Sequence(boxed) => {
-            assert!(matches!(boxed.kind, Kind::F32));
+        Kind::Matrix { rows, cols, element } => {
+            assert_eq!(rows, Some(3));
+            assert_eq!(cols, Some(1));
+            assert!(matches!(element.kind, Kind::F32));
         },
-        _ => panic!("Expected Vector3 to be described as a sequence"),
+        _ => panic!("Expected Vector3 to be described as a matrix"),
+    }
+}
+
+#[test]
+fn dvector_metadata_has_dynamic_extents() {
+    let data: Descriptor<()> = DVector::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A matrix from nalgebra"]));
+    match data.kind {
+        Kind::Matrix { rows, cols, element } => {
+            assert_eq!(rows, None);
+            assert_eq!(cols, Some(1));
+            assert!(matches!(element.kind, Kind::F32));
+        },
+        _ => panic!("Expected DVector to be described as a matrix"),
+    }
+}
+
+#[test]
+fn dmatrix_metadata_has_dynamic_extents() {
+    let data: Descriptor<()> = DMatrix::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A matrix from nalgebra"]));
+    match data.kind {
+        Kind::Matrix { rows, cols, element } => {
+            assert_eq!(rows, None);
+            assert_eq!(cols, None);
+            assert!(matches!(element.kind, Kind::F32));
+        },
+        _ => panic!("Expected DMatrix to be described as a matrix"),
     }
 }
 
@@ -33,18 +67,15 @@ fn vector3_metadata() {
 fn rotation3_metadata() {
     let data: Descriptor<()> = Rotation3::<f32>::metadata();
     assert_eq!(data.docs, Some(vec!["A 3D rotation matrix from nalgebra"]));
-    
+
     match data.kind {
-        Kind::Sequence(ref boxed) => {
-            // Check that the sequence is a sequence of rows, and that the inner type is also a sequence
-            match boxed.kind {
-                Kind::Sequence(ref inner_boxed) => {
-                    assert!(matches!(inner_boxed.kind, Kind::F32));
-                },
-                _ => panic!("Expected Rotation3 to be described as a sequence of sequences"),
-            }
+        Kind::Matrix { rows, cols, ref element } => {
+            // A rotation is a 3x3 matrix of scalar elements, not a nested sequence
+            assert_eq!(rows, Some(3));
+            assert_eq!(cols, Some(3));
+            assert!(matches!(element.kind, Kind::F32));
         },
-        _ => panic!("Expected Rotation3 to be described as a sequence of elements"),
+        _ => panic!("Expected Rotation3 to be described as a 3x3 matrix"),
     }
 }
 
@@ -59,21 +90,105 @@ fn isometry3_metadata() {
         let rotation_entry = children.iter().find(|e| e.label == "rotation").expect("Rotation metadata not found");
         assert_eq!(rotation_entry.docs, Some(vec!["The rotation component of the isometry"]));
         match rotation_entry.type_info.kind {
-            Kind::Sequence(_) => {}, // Expected sequence of rotation matrix elements
-            _ => panic!("Expected rotation to be described as a sequence"),
+            Kind::Matrix { .. } => {}, // Expected rotation matrix elements
+            _ => panic!("Expected rotation to be described as a matrix"),
         }
 
         let translation_entry = children.iter().find(|e| e.label == "translation").expect("Translation metadata not found");
         assert_eq!(translation_entry.docs, Some(vec!["The translation component of the isometry"]));
         match translation_entry.type_info.kind {
-            Kind::Sequence(_) => {}, // Expected sequence of translation vector elements
-            _ => panic!("Expected translation to be described as a vector"),
+            Kind::Matrix { .. } => {}, // Expected translation vector elements
+            _ => panic!("Expected translation to be described as a matrix"),
         }
     } else {
         panic!("Expected isometry to be described as a struct");
     }
 }
 
+#[test]
+fn point3_metadata() {
+    let data: Descriptor<()> = Point3::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A 3D point from nalgebra"]));
+
+    if let Kind::Struct { children, .. } = data.kind {
+        let coords_entry = children.iter().find(|e| e.label == "coords").expect("coords field not found");
+        match coords_entry.type_info.kind {
+            Kind::Matrix { rows, cols, .. } => {
+                assert_eq!(rows, Some(3));
+                assert_eq!(cols, Some(1));
+            },
+            _ => panic!("Expected coords to be described as a matrix"),
+        }
+    } else {
+        panic!("Expected Point3 to be described as a struct");
+    }
+}
+
+#[test]
+fn point2_metadata() {
+    let data: Descriptor<()> = Point2::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A 2D point from nalgebra"]));
+
+    if let Kind::Struct { children, .. } = data.kind {
+        let coords_entry = children.iter().find(|e| e.label == "coords").expect("coords field not found");
+        match coords_entry.type_info.kind {
+            Kind::Matrix { rows, cols, .. } => {
+                assert_eq!(rows, Some(2));
+                assert_eq!(cols, Some(1));
+            },
+            _ => panic!("Expected coords to be described as a matrix"),
+        }
+    } else {
+        panic!("Expected Point2 to be described as a struct");
+    }
+}
+
+#[test]
+fn translation3_metadata() {
+    let data: Descriptor<()> = Translation3::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A 3D translation from nalgebra"]));
+
+    if let Kind::Struct { children, .. } = data.kind {
+        children.iter().find(|e| e.label == "vector").expect("vector field not found");
+    } else {
+        panic!("Expected Translation3 to be described as a struct");
+    }
+}
+
+#[test]
+fn quaternion_metadata() {
+    let data: Descriptor<()> = Quaternion::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A quaternion from nalgebra"]));
+
+    if let Kind::Struct { children, .. } = data.kind {
+        for label in ["w", "i", "j", "k"] {
+            children.iter().find(|e| e.label == label).unwrap_or_else(|| panic!("{label} field not found"));
+        }
+    } else {
+        panic!("Expected Quaternion to be described as a struct");
+    }
+}
+
+#[test]
+fn unit_quaternion_metadata() {
+    let data: Descriptor<()> = UnitQuaternion::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A unit (normalized) quaternion from nalgebra, representing a rotation"]));
+    assert!(matches!(data.kind, Kind::Struct { .. }));
+}
+
+#[test]
+fn complex_metadata() {
+    let data: Descriptor<()> = Complex::<f32>::metadata();
+    assert_eq!(data.docs, Some(vec!["A complex number from nalgebra"]));
+
+    if let Kind::Struct { children, .. } = data.kind {
+        children.iter().find(|e| e.label == "re").expect("re field not found");
+        children.iter().find(|e| e.label == "im").expect("im field not found");
+    } else {
+        panic!("Expected Complex to be described as a struct");
+    }
+}
+
 #[derive(Default, MetadataKind)]
 pub struct Meta {
     pub display_name: &'static str,
@@ -110,8 +225,8 @@ fn transformation_metadata_with_meta() {
         assert_eq!(rotation_entry.metadata.display_name, "Rotation Matrix");
         assert_eq!(rotation_entry.metadata.description, "Rotation component as a 3D rotation matrix");
         match rotation_entry.type_info.kind {
-            Kind::Sequence(_) => {}, // Rotation is described as a sequence
-            _ => panic!("Expected rotation to be described as a sequence"),
+            Kind::Matrix { .. } => {}, // Rotation is described as a matrix
+            _ => panic!("Expected rotation to be described as a matrix"),
         }
         
         // Check translation field
@@ -119,8 +234,8 @@ fn transformation_metadata_with_meta() {
         assert_eq!(translation_entry.metadata.display_name, "Translation Vector");
         assert_eq!(translation_entry.metadata.description, "Translation component as a 3D vector");
         match translation_entry.type_info.kind {
-            Kind::Sequence(_) => {}, // Translation is described as a sequence
-            _ => panic!("Expected translation to be described as a sequence"),
+            Kind::Matrix { .. } => {}, // Translation is described as a matrix
+            _ => panic!("Expected translation to be described as a matrix"),
         }
     } else {
         panic!("Expected Transformation to be described as a struct");
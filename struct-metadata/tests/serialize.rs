@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use struct_metadata::{Deprecation, Descriptor, Entry, Kind, Stability, StabilityLevel};
+use struct_metadata::serialize::{write, Reader};
+
+fn sample_tree() -> Descriptor {
+    let mut root_metadata = HashMap::new();
+    root_metadata.insert("source", "serialize-test");
+
+    Descriptor {
+        docs: Some(vec!["A struct with a mix of child kinds"]),
+        metadata: root_metadata,
+        deprecation: None,
+        stability: Some(Stability { level: StabilityLevel::Unstable, since: None }),
+        kind: Kind::Struct {
+            name: "Sample".to_string(),
+            children: vec![
+                Entry {
+                    label: "flag".to_string(),
+                    docs: Some(vec!["A plain boolean field"]),
+                    metadata: HashMap::new(),
+                    type_info: Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::Bool, deprecation: None, stability: None },
+                    has_default: true,
+                    aliases: &["enabled", "on"],
+                    deprecation: Some(Deprecation { since: Some("1.2.0"), note: Some("use `enabled` instead") }),
+                    stability: None,
+                },
+                Entry {
+                    label: "matrix".to_string(),
+                    docs: None,
+                    metadata: HashMap::new(),
+                    type_info: Descriptor {
+                        docs: Some(vec!["A 2x2 matrix of counts"]),
+                        metadata: HashMap::new(),
+                        deprecation: None,
+                        stability: None,
+                        kind: Kind::Matrix {
+                            rows: Some(2),
+                            cols: Some(2),
+                            element: Box::new(Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::U64, deprecation: None, stability: None }),
+                        },
+                    },
+                    has_default: false,
+                    aliases: &[],
+                    deprecation: None,
+                    stability: Some(Stability { level: StabilityLevel::Stable, since: Some("1.0.0") }),
+                },
+                Entry {
+                    label: "alias".to_string(),
+                    docs: None,
+                    metadata: HashMap::new(),
+                    type_info: Descriptor {
+                        docs: None,
+                        metadata: HashMap::new(),
+                        deprecation: None,
+                        stability: None,
+                        kind: Kind::Aliased {
+                            name: "Name".to_string(),
+                            kind: Box::new(Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::String, deprecation: None, stability: None }),
+                        },
+                    },
+                    has_default: false,
+                    aliases: &[],
+                    deprecation: None,
+                    stability: None,
+                },
+            ],
+        },
+    }
+}
+
+#[test]
+fn round_trip_equals_original() {
+    let original = sample_tree();
+    let (blob, root) = write(&original);
+    let reader = Reader::new(&blob);
+    let decoded = reader.read_full(root);
+    assert_eq!(decoded, original);
+}
+
+#[test]
+fn reads_one_nested_field_without_decoding_siblings() {
+    let original = sample_tree();
+    let (blob, root) = write(&original);
+    let reader = Reader::new(&blob);
+
+    let top = reader.read(root);
+    let struct_metadata::serialize::DecodedKind::Struct { children, .. } = top.kind else {
+        panic!("expected Sample to decode as a struct");
+    };
+    // `children` are unresolved `Lazy<Entry>` positions: picking the "matrix" field by its
+    // known index (1) never decodes the "flag" or "alias" entries' headers, unlike scanning
+    // by label would.
+    assert_eq!(children.len(), 3);
+    let matrix_entry = reader.read_entry(children[1].position());
+    assert_eq!(matrix_entry.label, "matrix");
+
+    let matrix_descriptor = reader.read(matrix_entry.type_info.position());
+    assert_eq!(matrix_descriptor.docs, Some(vec!["A 2x2 matrix of counts".to_string()]));
+    match matrix_descriptor.kind {
+        struct_metadata::serialize::DecodedKind::Matrix { rows, cols, .. } => {
+            assert_eq!(rows, Some(2));
+            assert_eq!(cols, Some(2));
+        },
+        _ => panic!("expected matrix field to decode as a matrix"),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+struct SampleMetadata {
+    display_name: String,
+    priority: u64,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn round_trip_with_custom_metadata() {
+    use struct_metadata::serialize::{write_with_metadata, Reader};
+
+    let original = Descriptor {
+        docs: Some(vec!["A struct with non-default metadata"]),
+        metadata: SampleMetadata { display_name: "Sample".to_string(), priority: 3 },
+        deprecation: None,
+        stability: None,
+        kind: Kind::Struct {
+            name: "Sample".to_string(),
+            children: vec![Entry {
+                label: "flag".to_string(),
+                docs: None,
+                // `Entry::metadata` is always the default `HashMap`, even under a
+                // `Descriptor<M>` root with custom metadata.
+                metadata: HashMap::new(),
+                type_info: Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::Bool, deprecation: None, stability: None },
+                has_default: false,
+                aliases: &[],
+                deprecation: None,
+                stability: None,
+            }],
+        },
+    };
+
+    let (blob, root) = write_with_metadata(&original);
+    let reader = Reader::new(&blob);
+    let decoded: Descriptor<SampleMetadata> = reader.read_full_with_metadata(root);
+    assert_eq!(decoded, original);
+}
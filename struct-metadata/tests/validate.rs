@@ -0,0 +1,92 @@
+#![cfg(feature = "serde")]
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use struct_metadata::{Descriptor, Entry, Kind};
+use struct_metadata::validate::{validate, ValidationError};
+
+fn sample_descriptor() -> Descriptor {
+    Descriptor {
+        docs: None,
+        metadata: HashMap::new(),
+        deprecation: None,
+        stability: None,
+        kind: Kind::Struct {
+            name: "Sample".to_string(),
+            children: vec![
+                Entry {
+                    label: "name".to_string(),
+                    docs: None,
+                    metadata: HashMap::new(),
+                    type_info: Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::String, deprecation: None, stability: None },
+                    has_default: false,
+                    aliases: &[],
+                    deprecation: None,
+                    stability: None,
+                },
+                Entry {
+                    label: "age".to_string(),
+                    docs: None,
+                    metadata: HashMap::new(),
+                    type_info: Descriptor { docs: None, metadata: HashMap::new(), kind: Kind::U64, deprecation: None, stability: None },
+                    has_default: true,
+                    aliases: &["years"],
+                    deprecation: None,
+                    stability: None,
+                },
+            ],
+        },
+    }
+}
+
+#[test]
+fn valid_object_has_no_errors() {
+    let mut object = serde_json::Map::new();
+    object.insert("name".to_string(), Value::String("Ada".to_string()));
+    object.insert("age".to_string(), Value::Number(36.into()));
+
+    let errors = validate(&sample_descriptor(), &Value::Object(object));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn missing_field_without_default_is_reported() {
+    let object = serde_json::Map::new();
+
+    let errors = validate(&sample_descriptor(), &Value::Object(object));
+    assert_eq!(errors, vec![ValidationError::MissingField { path: vec![], label: "name".to_string() }]);
+}
+
+#[test]
+fn alias_satisfies_the_field_it_stands_in_for() {
+    let mut object = serde_json::Map::new();
+    object.insert("name".to_string(), Value::String("Ada".to_string()));
+    object.insert("years".to_string(), Value::Number(36.into()));
+
+    let errors = validate(&sample_descriptor(), &Value::Object(object));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unknown_field_is_reported() {
+    let mut object = serde_json::Map::new();
+    object.insert("name".to_string(), Value::String("Ada".to_string()));
+    object.insert("nickname".to_string(), Value::String("Lovelace".to_string()));
+
+    let errors = validate(&sample_descriptor(), &Value::Object(object));
+    assert_eq!(errors, vec![ValidationError::UnknownField { path: vec![], key: "nickname".to_string() }]);
+}
+
+#[test]
+fn scalar_type_mismatch_is_reported_with_its_path() {
+    let mut object = serde_json::Map::new();
+    object.insert("name".to_string(), Value::Bool(true));
+
+    let errors = validate(&sample_descriptor(), &Value::Object(object));
+    assert_eq!(
+        errors,
+        vec![ValidationError::TypeMismatch { path: vec!["name".to_string()], expected: "string", found: "bool" }]
+    );
+}